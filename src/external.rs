@@ -1,17 +1,57 @@
 #![allow(non_snake_case)]
 
+use std::ffi::c_void;
+
 use swift_rs::{swift, Bool, Int, SRObject, SRString};
 
+/// The completion callback invoked from the framework's reply block.
+///
+/// The first argument is the opaque user data pointer handed to
+/// `lacontext_evaluatePolicyAsync`. The remaining arguments carry the result:
+/// `success` is `true` when authentication succeeded, otherwise `code` holds the
+/// framework's `LAError` code and the `SRString` its `localizedDescription`.
+pub(crate) type EvaluatePolicyCallback = extern "C" fn(*mut c_void, Bool, Int, SRString);
+
 // func lacontext_new() -> LAContext
 swift!(pub(crate) fn lacontext_new() -> SRObject<LAContext>);
 
-// func lacontext_canEvaluatePolicy(context: LAContext, policy: LAPolicy) -> Bool
-swift!(pub(crate) fn lacontext_canEvaluatePolicy(context: &SRObject<LAContext>, policy: Int) -> Bool);
+// func lacontext_canEvaluatePolicy(context: LAContext, policy: LAPolicy) -> LACanEvaluateResult
+swift!(pub(crate) fn lacontext_canEvaluatePolicy(context: &SRObject<LAContext>, policy: Int) -> SRObject<LACanEvaluateResult>);
+
+// func lacontext_biometryType(context: LAContext) -> Int
+swift!(pub(crate) fn lacontext_biometryType(context: &SRObject<LAContext>) -> Int);
+
+// func lacontext_evaluatePolicyAsync(context: LAContext, policy: LAPolicy, reason: SRString, userData: UnsafeMutableRawPointer, callback: EvaluatePolicyCallback)
+swift!(pub(crate) fn lacontext_evaluatePolicyAsync(context: &SRObject<LAContext>, policy: Int, reason: &SRString, userData: *mut c_void, callback: EvaluatePolicyCallback));
+
+// func lacontext_setLocalizedCancelTitle(context: LAContext, title: SRString)
+swift!(pub(crate) fn lacontext_setLocalizedCancelTitle(context: &SRObject<LAContext>, title: &SRString));
 
-// func lacontext_evaluatePolicy(context: LAContext, policy: LAPolicy, reason: SRString)
-swift!(pub(crate) fn lacontext_evaluatePolicy(context: &SRObject<LAContext>, policy: Int, reason: &SRString) -> Bool);
+// func lacontext_setLocalizedFallbackTitle(context: LAContext, title: SRString)
+swift!(pub(crate) fn lacontext_setLocalizedFallbackTitle(context: &SRObject<LAContext>, title: &SRString));
+
+// func lacontext_setTouchIDAuthenticationAllowableReuseDuration(context: LAContext, seconds: Double)
+swift!(pub(crate) fn lacontext_setTouchIDAuthenticationAllowableReuseDuration(context: &SRObject<LAContext>, seconds: f64));
+
+// func lacontext_invalidate(context: LAContext)
+swift!(pub(crate) fn lacontext_invalidate(context: &SRObject<LAContext>));
 
 #[repr(C)]
 pub(crate) struct LAContext {
     interactionNotAllowed: Bool,
 }
+
+/// The out-struct returned by `lacontext_canEvaluatePolicy`.
+///
+/// `canEvaluatePolicy(_:error:)` returns a `Bool` and writes an `NSError` to an
+/// out-parameter when the policy can't be evaluated. `swift_rs` can't return
+/// tuples, so the Swift shim packs both into this object: `can_evaluate` is the
+/// native return value, and when it is `false` `code` holds the `LAError` code
+/// and `description` its `localizedDescription`. `code` is `0` when no error
+/// was produced.
+#[repr(C)]
+pub(crate) struct LACanEvaluateResult {
+    pub can_evaluate: Bool,
+    pub code: Int,
+    pub description: SRString,
+}
@@ -13,22 +13,32 @@
 //!     let local_authentication = LocalAuthentication::new();
 //!
 //!     // Try to authenticate the user
-//!     let authenticated = local_authentication.evaluate_policy(
+//!     let result = local_authentication.evaluate_policy(
 //!         LAPolicy::DeviceOwnerAuthenticationWithBiometrics,
 //!         "authenticate your user",
 //!     );
 //!
 //!     // Print the result
-//!     if authenticated {
-//!         println!("Welcome!");
-//!     } else {
-//!         println!("Not authenticated...");
+//!     match result {
+//!         Ok(()) => println!("Welcome!"),
+//!         Err(error) => println!("Not authenticated: {:?}", error),
 //!     }
 //! }
 //! ```
 
-use external::{lacontext_canEvaluatePolicy, lacontext_evaluatePolicy, lacontext_new, LAContext};
-use swift_rs::{Int, SRObject, SRString};
+use std::ffi::c_void;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use external::{
+    lacontext_biometryType, lacontext_canEvaluatePolicy, lacontext_evaluatePolicyAsync,
+    lacontext_invalidate, lacontext_new, lacontext_setLocalizedCancelTitle,
+    lacontext_setLocalizedFallbackTitle, lacontext_setTouchIDAuthenticationAllowableReuseDuration,
+    LAContext,
+};
+use swift_rs::{Bool, Int, SRObject, SRString};
 
 mod external;
 
@@ -46,7 +56,12 @@ impl LocalAuthentication {
 
     /// Checks if a [`LAPolicy`] can be evaluated.
     ///
-    /// This will return `true` if it can be evaluated, or `false` if it cannot.
+    /// Returns `Ok(true)` if the policy can be evaluated. When it cannot and the
+    /// framework reports an `NSError`, that error is threaded back as an
+    /// [`LAError`], letting callers tell apart missing hardware, an unenrolled
+    /// user, a lockout, or biometry being turned off in Settings (e.g.
+    /// [`LAError::BiometryNotEnrolled`] vs [`LAError::BiometryNotAvailable`]).
+    /// When it cannot and no error is reported, this returns `Ok(false)`.
     ///
     /// # Arguments
     /// * `policy` - The policy to evaluate
@@ -55,25 +70,116 @@ impl LocalAuthentication {
     ///
     /// # Examples
     /// ```
-    /// use localauthentication_rs::{LAPolicy, LocalAuthentication};
+    /// use localauthentication_rs::{LAError, LAPolicy, LocalAuthentication};
     ///
     /// fn main() {
     ///     // Create a new instance of LocalAuthentication
     ///     let local_authentication = LocalAuthentication::new();
     ///
     ///     // See if we can authenticate via biometrics
-    ///     let can_use_biometrics = local_authentication.can_evaluate_policy(LAPolicy::DeviceOwnerAuthenticationWithBiometrics);
+    ///     match local_authentication.can_evaluate_policy(LAPolicy::DeviceOwnerAuthenticationWithBiometrics) {
+    ///         Ok(true) => println!("Authenticating via biometrics!"),
+    ///         Ok(false) => println!("Falling back to..."),
+    ///         Err(LAError::BiometryNotEnrolled) => println!("Set up a fingerprint or face first!"),
+    ///         Err(error) => println!("Cannot use biometrics: {:?}", error),
+    ///     }
+    /// }
+    /// ```
+    pub fn can_evaluate_policy(&self, policy: LAPolicy) -> Result<bool, LAError> {
+        let result = unsafe { lacontext_canEvaluatePolicy(&self.context, policy.into()) };
+
+        // A non-zero code means the framework produced an `NSError`.
+        if result.code == 0 {
+            Ok(result.can_evaluate.into())
+        } else {
+            Err(result.code.into())
+        }
+    }
+
+    /// Sets how long a successful Touch ID authentication may be reused without
+    /// re-prompting the user.
     ///
-    ///     // Print the result
-    ///     if can_use_biometrics {
-    ///         println!("Authenticating via biometrics!");
-    ///     } else {
-    ///         println!("Falling back to...");
+    /// Within `seconds` of a successful evaluation, a subsequent evaluation on
+    /// this context succeeds immediately instead of showing the prompt again.
+    ///
+    /// **Apple Developer Documentation**: <https://developer.apple.com/documentation/localauthentication/lacontext/1514012-touchidauthenticationallowablere>
+    ///
+    /// # Arguments
+    /// * `seconds` - The grace window, in seconds, during which a recent authentication may be reused
+    pub fn set_reuse_duration(&self, seconds: f64) {
+        unsafe { lacontext_setTouchIDAuthenticationAllowableReuseDuration(&self.context, seconds) };
+    }
+
+    /// Invalidates the context.
+    ///
+    /// Any evaluation in progress is cancelled, and the context can no longer be
+    /// used to evaluate a policy. This is useful for tearing down a reused
+    /// context (e.g. when the app moves to the background) without dropping the
+    /// whole wrapper.
+    ///
+    /// **Apple Developer Documentation**: <https://developer.apple.com/documentation/localauthentication/lacontext/1514192-invalidate>
+    pub fn invalidate(&mut self) {
+        unsafe { lacontext_invalidate(&self.context) };
+    }
+
+    /// Sets the title of the cancel button shown in the authentication dialog.
+    ///
+    /// **Apple Developer Documentation**: <https://developer.apple.com/documentation/localauthentication/lacontext/1693595-localizedcanceltitle>
+    ///
+    /// # Arguments
+    /// * `title` - The title to display on the cancel button
+    pub fn with_cancel_title(self, title: &str) -> Self {
+        let string: SRString = title.into();
+        unsafe { lacontext_setLocalizedCancelTitle(&self.context, &string) };
+        self
+    }
+
+    /// Sets the title of the fallback button shown in the authentication dialog.
+    ///
+    /// Passing [`None`] hides the fallback button entirely (the framework does
+    /// this when the fallback title is an empty string).
+    ///
+    /// **Apple Developer Documentation**: <https://developer.apple.com/documentation/localauthentication/lacontext/1514183-localizedfallbacktitle>
+    ///
+    /// # Arguments
+    /// * `title` - The title to display on the fallback button, or [`None`] to hide it
+    pub fn with_fallback_title(self, title: Option<&str>) -> Self {
+        let string: SRString = title.unwrap_or("").into();
+        unsafe { lacontext_setLocalizedFallbackTitle(&self.context, &string) };
+        self
+    }
+
+    /// Returns the type of biometry supported by the device.
+    ///
+    /// The framework only populates `biometryType` after a call to
+    /// `canEvaluatePolicy`, so this method first evaluates
+    /// [`LAPolicy::DeviceOwnerAuthenticationWithBiometrics`] before reading the
+    /// value. If biometry is unavailable this returns [`LABiometryType::None`].
+    ///
+    /// **Apple Developer Documentation**: <https://developer.apple.com/documentation/localauthentication/lacontext/2867583-biometrytype>
+    ///
+    /// # Examples
+    /// ```
+    /// use localauthentication_rs::{LABiometryType, LocalAuthentication};
+    ///
+    /// fn main() {
+    ///     // Create a new instance of LocalAuthentication
+    ///     let local_authentication = LocalAuthentication::new();
+    ///
+    ///     // Tailor the prompt to the available biometry
+    ///     match local_authentication.biometry_type() {
+    ///         LABiometryType::FaceID => println!("Use Face ID"),
+    ///         LABiometryType::TouchID => println!("Use Touch ID"),
+    ///         LABiometryType::OpticID => println!("Use Optic ID"),
+    ///         _ => println!("Biometry unavailable"),
     ///     }
     /// }
     /// ```
-    pub fn can_evaluate_policy(&self, policy: LAPolicy) -> bool {
-        let value = unsafe { lacontext_canEvaluatePolicy(&self.context, policy.into()) };
+    pub fn biometry_type(&self) -> LABiometryType {
+        // `biometryType` is only populated once `canEvaluatePolicy` has run.
+        let _ = self.can_evaluate_policy(LAPolicy::DeviceOwnerAuthenticationWithBiometrics);
+
+        let value = unsafe { lacontext_biometryType(&self.context) };
         return value.into();
     }
 
@@ -100,22 +206,157 @@ impl LocalAuthentication {
     ///     let local_authentication = LocalAuthentication::new();
     ///
     ///     // Try to authenticate the user
-    ///     let success = local_authentication.evaluate_policy(
+    ///     let result = local_authentication.evaluate_policy(
     ///         LAPolicy::DeviceOwnerAuthenticationWithBiometrics,
     ///         "authenticate your user",
     ///     );
     ///
     ///     // Print the result
-    ///     if success {
-    ///         println!("Welcome!");
-    ///     } else {
-    ///         println!("Not authenticated...");
+    ///     match result {
+    ///         Ok(()) => println!("Welcome!"),
+    ///         Err(error) => println!("Not authenticated: {:?}", error),
     ///     }
     /// }
     /// ```
-    pub fn evaluate_policy(&self, policy: LAPolicy, reason: &str) -> bool {
+    pub fn evaluate_policy(&self, policy: LAPolicy, reason: &str) -> Result<(), LAError> {
+        // The blocking method is just the async core driven to completion on the
+        // calling thread.
+        block_on(self.evaluate_policy_async(policy, reason))
+    }
+
+    /// Asynchronously evaluates a [`LAPolicy`].
+    ///
+    /// Unlike [`Self::evaluate_policy`], this does not park the calling thread:
+    /// the returned future resolves once the framework's reply block fires,
+    /// making it safe to call from a UI thread. The result is delivered through
+    /// shared state the Swift completion callback fills in before waking the
+    /// future.
+    ///
+    /// # Arguments
+    /// * `policy` - The policy to evaluate
+    /// * `reason` - The reason shown to the user as to why you are trying to authenticate. Will be formatted as follows: `[binary] is trying to {reason}`
+    ///
+    /// **Apple Developer Documentation**: <https://developer.apple.com/documentation/localauthentication/lacontext/1514176-evaluatepolicy>
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use localauthentication_rs::{LAPolicy, LocalAuthentication};
+    ///
+    /// async fn authenticate() {
+    ///     // Create a new instance of LocalAuthentication
+    ///     let local_authentication = LocalAuthentication::new();
+    ///
+    ///     // Try to authenticate the user without blocking
+    ///     let result = local_authentication
+    ///         .evaluate_policy_async(
+    ///             LAPolicy::DeviceOwnerAuthenticationWithBiometrics,
+    ///             "authenticate your user",
+    ///         )
+    ///         .await;
+    ///
+    ///     // Print the result
+    ///     match result {
+    ///         Ok(()) => println!("Welcome!"),
+    ///         Err(error) => println!("Not authenticated: {:?}", error),
+    ///     }
+    /// }
+    /// ```
+    pub fn evaluate_policy_async(
+        &self,
+        policy: LAPolicy,
+        reason: &str,
+    ) -> impl Future<Output = Result<(), LAError>> {
+        let state = Arc::new(Mutex::new(EvaluateState::default()));
         let string: SRString = reason.into();
-        return unsafe { lacontext_evaluatePolicy(&self.context, policy.into(), &string) }.into();
+
+        // Hand a strong reference to the Swift side; the callback reclaims it.
+        let user_data = Arc::into_raw(state.clone()) as *mut c_void;
+        unsafe {
+            lacontext_evaluatePolicyAsync(
+                &self.context,
+                policy.into(),
+                &string,
+                user_data,
+                evaluate_policy_callback,
+            );
+        }
+
+        EvaluatePolicyFuture { state }
+    }
+}
+
+/// The shared state bridging the Swift completion callback and the future.
+#[derive(Default)]
+struct EvaluateState {
+    result: Option<Result<(), LAError>>,
+    waker: Option<Waker>,
+}
+
+/// The future returned by [`LocalAuthentication::evaluate_policy_async`].
+struct EvaluatePolicyFuture {
+    state: Arc<Mutex<EvaluateState>>,
+}
+
+impl Future for EvaluatePolicyFuture {
+    type Output = Result<(), LAError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Invoked from the framework's reply block with the evaluation result.
+extern "C" fn evaluate_policy_callback(
+    user_data: *mut c_void,
+    success: Bool,
+    code: Int,
+    _description: SRString,
+) {
+    // Reclaim the strong reference handed to the Swift side in
+    // `evaluate_policy_async`, dropping it when this callback returns.
+    let state = unsafe { Arc::from_raw(user_data as *const Mutex<EvaluateState>) };
+
+    let result = if success.into() {
+        Ok(())
+    } else {
+        Err(code.into())
+    };
+
+    let mut guard = state.lock().unwrap();
+    guard.result = Some(result);
+    if let Some(waker) = guard.waker.take() {
+        waker.wake();
+    }
+}
+
+/// Drives a future to completion on the current thread, parking between polls.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    let mut future = future;
+    // SAFETY: `future` is owned here and never moved again before being dropped.
+    let mut future = unsafe { Pin::new_unchecked(&mut future) };
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
     }
 }
 
@@ -160,3 +401,99 @@ impl From<LAPolicy> for Int {
         }
     }
 }
+
+/// The set of biometry types a device can support.
+///
+/// **Apple Developer Documentation**: <https://developer.apple.com/documentation/localauthentication/labiometrytype>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LABiometryType {
+    /// 0. No biometry is supported.
+    None,
+
+    /// 1. The device supports Touch ID.
+    TouchID,
+
+    /// 2. The device supports Face ID.
+    FaceID,
+
+    /// 4. The device supports Optic ID.
+    OpticID,
+
+    /// A biometry type that this crate does not map.
+    Unknown,
+}
+
+impl From<Int> for LABiometryType {
+    fn from(value: Int) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::TouchID,
+            2 => Self::FaceID,
+            4 => Self::OpticID,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// The set of errors that the framework can report while evaluating a policy.
+///
+/// These mirror the codes of [LAError](https://developer.apple.com/documentation/localauthentication/laerror),
+/// with an [`Unknown`](LAError::Unknown) fallback carrying the raw code for any
+/// value this crate does not map yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LAError {
+    /// -1. The user failed to provide valid credentials.
+    AuthenticationFailed,
+
+    /// -2. The user tapped the cancel button in the authentication dialog.
+    UserCancel,
+
+    /// -3. The user tapped the fallback button in the authentication dialog.
+    UserFallback,
+
+    /// -4. The system cancelled authentication.
+    SystemCancel,
+
+    /// -5. A passcode isn't set on the device.
+    PasscodeNotSet,
+
+    /// -6. Biometry is not available on the device.
+    BiometryNotAvailable,
+
+    /// -7. The user has no enrolled biometric identities.
+    BiometryNotEnrolled,
+
+    /// -8. Biometry is locked because there were too many failed attempts.
+    BiometryLockout,
+
+    /// -9. The app cancelled authentication.
+    AppCancel,
+
+    /// -10. The context was invalidated.
+    InvalidContext,
+
+    /// -1004. Displaying the required authentication user interface is forbidden.
+    NotInteractive,
+
+    /// An error code that this crate does not map, carrying the raw value.
+    Unknown(i64),
+}
+
+impl From<Int> for LAError {
+    fn from(value: Int) -> Self {
+        match value {
+            -1 => Self::AuthenticationFailed,
+            -2 => Self::UserCancel,
+            -3 => Self::UserFallback,
+            -4 => Self::SystemCancel,
+            -5 => Self::PasscodeNotSet,
+            -6 => Self::BiometryNotAvailable,
+            -7 => Self::BiometryNotEnrolled,
+            -8 => Self::BiometryLockout,
+            -9 => Self::AppCancel,
+            -10 => Self::InvalidContext,
+            -1004 => Self::NotInteractive,
+            other => Self::Unknown(other as i64),
+        }
+    }
+}